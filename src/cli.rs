@@ -69,6 +69,10 @@ pub async fn cli() -> Result<(), Box<dyn Error>> {
             command("to-toml", to_toml::to_toml),
             Arc::new(Where),
             Arc::new(Config),
+            command("alias", alias::alias),
+            command("jobs", jobs::jobs),
+            command("wait", wait::wait),
+            command("fg", fg::fg),
             command("sort-by", sort_by::sort_by),
         ]);
 
@@ -97,25 +101,54 @@ pub async fn cli() -> Result<(), Box<dyn Error>> {
     })
     .expect("Error setting Ctrl-C handler");
 
+    let mut buffer = String::new();
+
     loop {
         if ctrl_c.load(Ordering::SeqCst) {
             ctrl_c.store(false, Ordering::SeqCst);
             if let ShellError::String(s) = ShellError::string("CTRL-C") {
                 context.host.lock().unwrap().stdout(&format!("{:?}", s));
             }
+            buffer.clear();
             continue;
         }
 
-        let readline = rl.readline(&format!(
-            "{}{}> ",
-            context.env.lock().unwrap().cwd().display().to_string(),
-            match current_branch() {
-                Some(s) => format!("({})", s),
-                None => "".to_string(),
+        let prompt = if buffer.is_empty() {
+            format!(
+                "{}{}> ",
+                context.env.lock().unwrap().cwd().display().to_string(),
+                match current_branch() {
+                    Some(s) => format!("({})", s),
+                    None => "".to_string(),
+                }
+            )
+        } else {
+            "...> ".to_string()
+        };
+
+        let mut pending_input = false;
+
+        let readline = match rl.readline(&prompt) {
+            Ok(line) => {
+                if !buffer.is_empty() {
+                    buffer.push('\n');
+                }
+                buffer.push_str(&line);
+
+                if needs_more_input(&buffer) {
+                    continue;
+                }
+
+                Ok(std::mem::replace(&mut buffer, String::new()))
             }
-        ));
+            Err(err) => {
+                pending_input = !buffer.is_empty();
+                buffer.clear();
+                Err(err)
+            }
+        };
 
-        match process_line(readline, &mut context).await {
+        match process_line(readline, &mut context, pending_input).await {
             LineResult::Success(line) => {
                 rl.add_history_entry(line.clone());
             }
@@ -148,6 +181,12 @@ pub async fn cli() -> Result<(), Box<dyn Error>> {
                     .stdout(&format!("Missing property {}", subpath)),
 
                 ShellError::String(s) => context.host.lock().unwrap().stdout(&format!("{:?}", s)),
+
+                ShellError::Incomplete => context
+                    .host
+                    .lock()
+                    .unwrap()
+                    .stdout("Unexpected end of input"),
             },
 
             LineResult::Break => {
@@ -201,14 +240,70 @@ impl std::ops::Try for LineResult {
     }
 }
 
-async fn process_line(readline: Result<String, ReadlineError>, ctx: &mut Context) -> LineResult {
+// A lightweight, REPL-side lexical check for unterminated input: an open
+// quote, unbalanced brackets, or a trailing pipe. This intentionally doesn't
+// reuse `crate::parser::parse` -- the tokenizer bails out with a regular
+// parse error on a truncated pipeline rather than distinguishing "incomplete"
+// from "invalid", so the readline loop has to make that call itself before
+// ever handing the buffer to the real parser.
+//
+// This is a deliberately shallow, REPL-local heuristic rather than a real
+// parser-level distinction: it only keeps the multi-line prompt open while
+// more input is still arriving from the same readline session. The one case
+// that can reach the point of no more input arriving -- Ctrl-D while
+// `buffer` is non-empty -- is surfaced as `ShellError::Incomplete` in
+// `process_line` below. Teaching the actual tokenizer to distinguish
+// incomplete from invalid input (so e.g. a file passed on the command line
+// could get the same diagnosis) is out of scope here and would belong in
+// `crate::parser` instead.
+fn needs_more_input(text: &str) -> bool {
+    let mut depth = 0i32;
+    let mut quote: Option<char> = None;
+    let mut escaped = false;
+
+    for ch in text.chars() {
+        if let Some(open) = quote {
+            if escaped {
+                escaped = false;
+            } else if ch == '\\' {
+                escaped = true;
+            } else if ch == open {
+                quote = None;
+            }
+            continue;
+        }
+
+        match ch {
+            '"' | '\'' => quote = Some(ch),
+            '(' | '[' | '{' => depth += 1,
+            ')' | ']' | '}' => depth -= 1,
+            _ => {}
+        }
+    }
+
+    quote.is_some() || depth > 0 || text.trim_end().ends_with('|')
+}
+
+async fn process_line(
+    readline: Result<String, ReadlineError>,
+    ctx: &mut Context,
+    pending_input: bool,
+) -> LineResult {
     match &readline {
         Ok(line) if line.trim() == "exit" => LineResult::Break,
 
         Ok(line) if line.trim() == "" => LineResult::Success(line.clone()),
 
         Ok(line) => {
-            let result = match crate::parser::parse(&line) {
+            let trimmed = line.trim_end();
+            let background = trimmed.ends_with('&') && !trimmed.ends_with("&&");
+            let command_text = if background {
+                trimmed[..trimmed.len() - 1].trim_end()
+            } else {
+                line.as_str()
+            };
+
+            let result = match crate::parser::parse(command_text) {
                 Err(err) => {
                     return LineResult::Error(err);
                 }
@@ -219,107 +314,41 @@ async fn process_line(readline: Result<String, ReadlineError>, ctx: &mut Context
             debug!("=== Parsed ===");
             debug!("{:#?}", result);
 
-            let mut pipeline = classify_pipeline(&result, ctx)?;
-
-            match pipeline.commands.last() {
-                Some(ClassifiedCommand::Sink(_)) => {}
-                Some(ClassifiedCommand::External(_)) => {}
-                _ => pipeline.commands.push(ClassifiedCommand::Sink(SinkCommand {
-                    command: sink("autoview", autoview::autoview),
-                    args: Args {
-                        positional: vec![],
-                        named: indexmap::IndexMap::new(),
-                    },
-                })),
-            }
+            let pipeline = classify_pipeline(&result, ctx)?;
 
-            let mut input = ClassifiedInputStream::new();
+            if background {
+                // Reserve the job (and mark it running) before spawning: if the
+                // pipeline finished and called `finish` first, a later `insert`
+                // would re-add it as running with no task left to reap it.
+                let job_id = ctx.jobs.lock().unwrap().reserve();
+                let mut job_ctx = ctx.clone();
 
-            let mut iter = pipeline.commands.into_iter().peekable();
-
-            loop {
-                let item: Option<ClassifiedCommand> = iter.next();
-                let next: Option<&ClassifiedCommand> = iter.peek();
-
-                input = match (item, next) {
-                    (None, _) => break,
-
-                    (Some(ClassifiedCommand::Expr(_)), _) => {
-                        return LineResult::Error(ShellError::unimplemented(
-                            "Expression-only commands",
-                        ))
-                    }
-
-                    (_, Some(ClassifiedCommand::Expr(_))) => {
-                        return LineResult::Error(ShellError::unimplemented(
-                            "Expression-only commands",
-                        ))
-                    }
-
-                    (Some(ClassifiedCommand::Sink(_)), Some(_)) => {
-                        return LineResult::Error(ShellError::string("Commands like table, save, and autoview must come last in the pipeline"))
+                let handle = async_std::task::spawn(async move {
+                    if let Err(err) = run_classified_pipeline(pipeline, &mut job_ctx).await {
+                        job_ctx.host.lock().unwrap().stdout(&format!("{:?}", err));
                     }
+                    job_ctx.jobs.lock().unwrap().finish(job_id);
+                });
 
-                    (Some(ClassifiedCommand::Sink(left)), None) => {
-                        let input_vec: Vec<Value> = input.objects.collect().await;
-                        left.run(
-                            ctx,
-                            input_vec,
-                        )?;
-                        break;
-                    }
-
-                    (
-                        Some(ClassifiedCommand::Internal(left)),
-                        Some(ClassifiedCommand::External(_)),
-                    ) => match left.run(ctx, input).await {
-                        Ok(val) => ClassifiedInputStream::from_input_stream(val),
-                        Err(err) => return LineResult::Error(err),
-                    },
-
-                    (
-                        Some(ClassifiedCommand::Internal(left)),
-                        Some(_),
-                    ) => match left.run(ctx, input).await {
-                        Ok(val) => ClassifiedInputStream::from_input_stream(val),
-                        Err(err) => return LineResult::Error(err),
-                    },
-
-                    (Some(ClassifiedCommand::Internal(left)), None) => {
-                        match left.run(ctx, input).await {
-                            Ok(val) => ClassifiedInputStream::from_input_stream(val),
-                            Err(err) => return LineResult::Error(err),
-                        }
-                    }
+                ctx.jobs.lock().unwrap().attach(job_id, handle);
+                ctx.host
+                    .lock()
+                    .unwrap()
+                    .stdout(&format!("[{}] started in background", job_id));
 
-                    (
-                        Some(ClassifiedCommand::External(left)),
-                        Some(ClassifiedCommand::External(_)),
-                    ) => match left.run(ctx, input, StreamNext::External).await {
-                        Ok(val) => val,
-                        Err(err) => return LineResult::Error(err),
-                    },
-
-                    (
-                        Some(ClassifiedCommand::External(left)),
-                        Some(_),
-                    ) => match left.run(ctx, input, StreamNext::Internal).await {
-                        Ok(val) => val,
-                        Err(err) => return LineResult::Error(err),
-                    },
-
-                    (Some(ClassifiedCommand::External(left)), None) => {
-                        match left.run(ctx, input, StreamNext::Last).await {
-                            Ok(val) => val,
-                            Err(err) => return LineResult::Error(err),
-                        }
-                    }
-                }
+                return LineResult::Success(line.to_string());
             }
 
+            run_classified_pipeline(pipeline, ctx).await?;
+
             LineResult::Success(line.to_string())
         }
         Err(ReadlineError::Interrupted) => LineResult::Error(ShellError::string("CTRL-C")),
+        // Ctrl-D while `buffer` still held an unterminated quote/bracket/pipe
+        // is the one place this REPL can tell "incomplete" and "invalid"
+        // input apart before ever reaching `crate::parser::parse` -- report
+        // it as such instead of silently treating it like a plain exit.
+        Err(ReadlineError::Eof) if pending_input => LineResult::Error(ShellError::Incomplete),
         Err(ReadlineError::Eof) => {
             println!("CTRL-D");
             LineResult::Break
@@ -331,20 +360,213 @@ async fn process_line(readline: Result<String, ReadlineError>, ctx: &mut Context
     }
 }
 
+// Runs a fully-classified pipeline to completion, threading the stream from
+// one command into the next. Used both for the synchronous foreground path
+// and for pipelines launched into the background with a trailing `&`.
+async fn run_classified_pipeline(
+    pipeline: ClassifiedPipeline,
+    ctx: &mut Context,
+) -> Result<(), ShellError> {
+    let mut pipeline = pipeline;
+
+    match pipeline.commands.last() {
+        Some(ClassifiedCommand::Sink(_)) => {}
+        Some(ClassifiedCommand::External(_)) => {}
+        _ => pipeline.commands.push(ClassifiedCommand::Sink(SinkCommand {
+            command: sink("autoview", autoview::autoview),
+            args: Args {
+                positional: vec![],
+                named: indexmap::IndexMap::new(),
+            },
+        })),
+    }
+
+    let mut input = ClassifiedInputStream::new();
+
+    let mut iter = pipeline.commands.into_iter().peekable();
+
+    loop {
+        let item: Option<ClassifiedCommand> = iter.next();
+        let next: Option<&ClassifiedCommand> = iter.peek();
+
+        input = match (item, next) {
+            (None, _) => break,
+
+            (Some(ClassifiedCommand::Expr(expr)), _) => {
+                // An expression stage still sees whatever came down the pipe: evaluate
+                // it once per incoming item with `$it` bound to that item, the same way
+                // `get`/`pick`/`where` do. With nothing upstream (expr is the first
+                // stage, e.g. a bare `1 + 2`) fall back to a single evaluation against
+                // an empty scope.
+                let upstream: Vec<Value> = input.objects.collect().await;
+
+                let values = if upstream.is_empty() {
+                    let scope = Scope::empty();
+                    vec![crate::evaluate::evaluate_expr(&expr, &scope)?]
+                } else {
+                    upstream
+                        .into_iter()
+                        .map(|it| crate::evaluate::evaluate_expr(&expr, &Scope::it(it)))
+                        .collect::<Result<Vec<Value>, ShellError>>()?
+                };
+
+                ClassifiedInputStream::from_input_stream(futures::stream::iter(values).boxed())
+            }
+
+            (Some(ClassifiedCommand::Sink(_)), Some(_)) => {
+                return Err(ShellError::string(
+                    "Commands like table, save, and autoview must come last in the pipeline",
+                ))
+            }
+
+            (Some(ClassifiedCommand::Sink(left)), None) => {
+                let input_vec: Vec<Value> = input.objects.collect().await;
+                left.run(ctx, input_vec)?;
+                break;
+            }
+
+            (
+                Some(ClassifiedCommand::Internal(left)),
+                Some(ClassifiedCommand::External(_)),
+            ) => match left.run(ctx, input).await {
+                Ok(val) => ClassifiedInputStream::from_input_stream(val),
+                Err(err) => return Err(err),
+            },
+
+            (Some(ClassifiedCommand::Internal(left)), Some(_)) => {
+                match left.run(ctx, input).await {
+                    Ok(val) => ClassifiedInputStream::from_input_stream(val),
+                    Err(err) => return Err(err),
+                }
+            }
+
+            (Some(ClassifiedCommand::Internal(left)), None) => match left.run(ctx, input).await {
+                Ok(val) => ClassifiedInputStream::from_input_stream(val),
+                Err(err) => return Err(err),
+            },
+
+            (
+                Some(ClassifiedCommand::External(left)),
+                Some(ClassifiedCommand::External(_)),
+            ) => match left.run(ctx, input, StreamNext::External).await {
+                Ok(val) => val,
+                Err(err) => return Err(err),
+            },
+
+            (Some(ClassifiedCommand::External(left)), Some(_)) => {
+                match left.run(ctx, input, StreamNext::Internal).await {
+                    Ok(val) => val,
+                    Err(err) => return Err(err),
+                }
+            }
+
+            (Some(ClassifiedCommand::External(left)), None) => {
+                match left.run(ctx, input, StreamNext::Last).await {
+                    Ok(val) => val,
+                    Err(err) => return Err(err),
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+// Aliases can expand to calls to other aliases (`alias a = ls`, `alias b = a`),
+// so expansion has to recurse. Cycles are cut by `expand_alias` (see below);
+// this cap bounds how long a chain of distinct aliases may get.
+const MAX_ALIAS_EXPANSION_DEPTH: usize = 16;
+
 fn classify_pipeline(
     pipeline: &Pipeline,
     context: &Context,
 ) -> Result<ClassifiedPipeline, ShellError> {
-    let commands: Result<Vec<_>, _> = pipeline
-        .commands
-        .iter()
-        .cloned()
-        .map(|item| classify_command(&item, context))
-        .collect();
-
-    Ok(ClassifiedPipeline {
-        commands: commands?,
-    })
+    let mut commands = vec![];
+    let mut active_aliases = vec![];
+
+    for item in pipeline.commands.iter() {
+        classify_item(item, context, &mut commands, &mut active_aliases, 0)?;
+    }
+
+    Ok(ClassifiedPipeline { commands })
+}
+
+fn classify_item(
+    item: &Expression,
+    context: &Context,
+    commands: &mut Vec<ClassifiedCommand>,
+    active_aliases: &mut Vec<String>,
+    depth: usize,
+) -> Result<(), ShellError> {
+    if depth > MAX_ALIAS_EXPANSION_DEPTH {
+        return Err(ShellError::string(
+            "Alias expansion is too deep (too many nested aliases)",
+        ));
+    }
+
+    match expand_alias(item, context, active_aliases)? {
+        Some((name, expanded)) => {
+            active_aliases.push(name);
+            for expanded_item in expanded.commands.iter() {
+                classify_item(expanded_item, context, commands, active_aliases, depth + 1)?;
+            }
+            active_aliases.pop();
+        }
+        None => commands.push(classify_command(item, context)?),
+    }
+
+    Ok(())
+}
+
+// If `item` is a bare call to a registered alias, re-parse and splice in its
+// body so the rest of classification never has to know aliases exist.
+//
+// `active_aliases` holds the names of aliases we're already expanding the
+// body of, innermost last. The classic self-wrapping idiom (`alias ls = ls
+// -la`) re-mentions its own name inside its own body on purpose -- that
+// occurrence is meant to reach the real `ls` command, not re-trigger the
+// alias, so a name already on the stack is treated as a literal call instead
+// of expanded again. The same check ends a cycle between two or more aliases
+// at its first repeated name, which is then classified as a plain command.
+fn expand_alias(
+    item: &Expression,
+    context: &Context,
+    active_aliases: &[String],
+) -> Result<Option<(String, Pipeline)>, ShellError> {
+    if let Expression {
+        expr: RawExpression::Call(call),
+        ..
+    } = item
+    {
+        if let Expression {
+            expr: RawExpression::Leaf(Leaf::Bare(name)),
+            ..
+        } = &call.name
+        {
+            let name = name.to_string();
+
+            if active_aliases.iter().any(|active| active == &name) {
+                return Ok(None);
+            }
+
+            if let Some(body) = context.get_alias(&name) {
+                // The alias body is a fixed pipeline; we don't have a slot to
+                // splice caller-supplied args into, so reject them outright
+                // instead of silently running the alias as if they weren't
+                // there.
+                if call.args.is_some() {
+                    return Err(ShellError::string(&format!(
+                        "'{}' is an alias and doesn't take arguments",
+                        name
+                    )));
+                }
+
+                return crate::parser::parse(&body).map(|pipeline| Some((name, pipeline)));
+            }
+        }
+    }
+
+    Ok(None)
 }
 
 fn classify_command(
@@ -415,9 +637,70 @@ fn classify_command(
             (_, Some(_)) => Err(ShellError::string("Unimplemented dynamic command")),
         }
     } else {
-        Err(ShellError::string(&format!(
-            "Unimplemented command that is just an expression (2) -- {:?}",
-            command
-        )))
+        match &command.expr {
+            RawExpression::Leaf(_) | RawExpression::Binary(_) | RawExpression::Path(_) => {
+                Ok(ClassifiedCommand::Expr(command.clone()))
+            }
+
+            _ => Err(ShellError::string(&format!(
+                "Unimplemented command that is just an expression (2) -- {:?}",
+                command
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn alias_context(name: &str, body: &str) -> Context {
+        let context = Context::basic().expect("Context::basic");
+        context.add_alias(name, body);
+        context
+    }
+
+    #[test]
+    fn expands_a_quoted_piped_alias_body() {
+        let context = alias_context("ll", "ls | sort-by name");
+        let pipeline = crate::parser::parse("ll").expect("parse");
+
+        let classified = classify_pipeline(&pipeline, &context).expect("classify");
+
+        assert_eq!(classified.commands.len(), 2);
+
+        match &classified.commands[0] {
+            ClassifiedCommand::External(cmd) => assert_eq!(cmd.name, "ls"),
+            _ => panic!("expected the alias body's first stage to be `ls`"),
+        }
+        match &classified.commands[1] {
+            ClassifiedCommand::External(cmd) => assert_eq!(cmd.name, "sort-by"),
+            _ => panic!("expected the alias body's second stage to be `sort-by`"),
+        }
+    }
+
+    #[test]
+    fn self_wrapping_alias_reaches_the_real_command() {
+        let context = alias_context("ls", "ls -la");
+        let pipeline = crate::parser::parse("ls").expect("parse");
+
+        let classified = classify_pipeline(&pipeline, &context).expect("classify");
+
+        assert_eq!(classified.commands.len(), 1);
+        match &classified.commands[0] {
+            ClassifiedCommand::External(cmd) => {
+                assert_eq!(cmd.name, "ls");
+                assert_eq!(cmd.args, vec!["-la".to_string()]);
+            }
+            _ => panic!("expected the alias's own name inside its body to reach the real `ls`"),
+        }
+    }
+
+    #[test]
+    fn needs_more_input_detects_unterminated_constructs() {
+        assert!(needs_more_input("\"unterminated"));
+        assert!(needs_more_input("echo (1 + 2"));
+        assert!(needs_more_input("ls |"));
+        assert!(!needs_more_input("ls -la"));
     }
 }
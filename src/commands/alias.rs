@@ -0,0 +1,32 @@
+use crate::commands::command::CommandArgs;
+use crate::errors::ShellError;
+use crate::prelude::*;
+
+// `alias <name> <body>` stores `body` verbatim on the context's alias table;
+// `classify_pipeline` re-parses it and splices it in wherever `<name>` is
+// later called bare. There's no `=` token: `body` is just the second
+// positional argument, taken as one raw string. A piped body has to be
+// quoted (`alias ll "ls | sort-by name"`) since an unquoted `|` is the
+// pipeline separator and would split into two pipeline stages before
+// `alias` ever sees it.
+pub fn alias(args: CommandArgs) -> Result<OutputStream, ShellError> {
+    const USAGE: &str = "alias: expected a name and a body, e.g. `alias ll \"ls | sort-by name\"`";
+
+    let name = args
+        .args
+        .positional
+        .get(0)
+        .ok_or_else(|| ShellError::string(USAGE))?
+        .as_string()?;
+
+    let body = args
+        .args
+        .positional
+        .get(1)
+        .ok_or_else(|| ShellError::string(USAGE))?
+        .as_string()?;
+
+    args.ctx.add_alias(&name, &body);
+
+    Ok(OutputStream::empty())
+}
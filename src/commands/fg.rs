@@ -0,0 +1,22 @@
+use crate::commands::command::CommandArgs;
+use crate::commands::wait::job_id;
+use crate::errors::ShellError;
+use crate::prelude::*;
+
+// With background output already routed through `context.host` as it runs,
+// bringing a job to the foreground just means blocking the REPL on it.
+pub fn fg(args: CommandArgs) -> Result<OutputStream, ShellError> {
+    let id = job_id(&args)?;
+
+    let handle = args
+        .ctx
+        .jobs
+        .lock()
+        .unwrap()
+        .take_handle(id)
+        .ok_or_else(|| ShellError::string(&format!("fg: no running job {}", id)))?;
+
+    async_std::task::block_on(handle);
+
+    Ok(OutputStream::empty())
+}
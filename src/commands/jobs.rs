@@ -0,0 +1,27 @@
+use crate::commands::command::CommandArgs;
+use crate::errors::ShellError;
+use crate::object::Value;
+use crate::prelude::*;
+use indexmap::IndexMap;
+
+// One row per job, with `id`/`status` as separate columns rather than a
+// pre-formatted string, so the output composes with the rest of the command
+// set (`jobs | where status == Running`, `jobs | get id`, ...).
+pub fn jobs(args: CommandArgs) -> Result<OutputStream, ShellError> {
+    let rows = args
+        .ctx
+        .jobs
+        .lock()
+        .unwrap()
+        .list()
+        .into_iter()
+        .map(|(id, status)| {
+            let mut row = IndexMap::new();
+            row.insert("id".to_string(), Value::int(id as i64));
+            row.insert("status".to_string(), Value::string(format!("{:?}", status)));
+            Value::Object(row)
+        })
+        .collect::<Vec<_>>();
+
+    Ok(OutputStream::from(rows))
+}
@@ -0,0 +1,31 @@
+use crate::commands::command::CommandArgs;
+use crate::errors::ShellError;
+use crate::prelude::*;
+
+pub fn wait(args: CommandArgs) -> Result<OutputStream, ShellError> {
+    let id = job_id(&args)?;
+
+    let handle = args
+        .ctx
+        .jobs
+        .lock()
+        .unwrap()
+        .take_handle(id)
+        .ok_or_else(|| ShellError::string(&format!("wait: no running job {}", id)))?;
+
+    async_std::task::block_on(handle);
+
+    Ok(OutputStream::empty())
+}
+
+pub(crate) fn job_id(args: &CommandArgs) -> Result<usize, ShellError> {
+    let raw = args
+        .args
+        .positional
+        .get(0)
+        .ok_or_else(|| ShellError::string("expected a job id"))?
+        .as_string()?;
+
+    raw.parse::<usize>()
+        .map_err(|_| ShellError::string(&format!("'{}' is not a job id", raw)))
+}
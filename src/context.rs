@@ -0,0 +1,121 @@
+use std::fs::File;
+use std::io::{BufRead, BufReader, Write};
+use std::sync::{Arc, Mutex};
+
+use indexmap::IndexMap;
+
+use crate::commands::command::Command;
+use crate::env::Environment;
+use crate::host::Host;
+use crate::jobs::JobTable;
+
+// Plain `name\tbody` lines, one alias per line -- the same ad-hoc plain-text
+// approach `cli()` already uses for `history.txt`.
+const ALIAS_FILE: &str = "aliases.txt";
+
+#[derive(Clone)]
+pub struct Context {
+    commands: Arc<Mutex<IndexMap<String, Arc<dyn Command>>>>,
+    sinks: Arc<Mutex<IndexMap<String, Arc<dyn Command>>>>,
+    aliases: Arc<Mutex<IndexMap<String, String>>>,
+
+    pub host: Arc<Mutex<dyn Host + Send>>,
+    pub env: Arc<Mutex<Environment>>,
+    pub jobs: Arc<Mutex<JobTable>>,
+}
+
+impl Context {
+    pub fn basic() -> Result<Context, Box<dyn std::error::Error>> {
+        Ok(Context {
+            commands: Arc::new(Mutex::new(IndexMap::new())),
+            sinks: Arc::new(Mutex::new(IndexMap::new())),
+            aliases: Arc::new(Mutex::new(load_aliases())),
+            host: Arc::new(Mutex::new(crate::host::BasicHost)),
+            env: Arc::new(Mutex::new(Environment::basic()?)),
+            jobs: Arc::new(Mutex::new(JobTable::new())),
+        })
+    }
+
+    pub fn add_commands(&mut self, commands: Vec<Arc<dyn Command>>) {
+        let mut registry = self.commands.lock().unwrap();
+        for command in commands {
+            registry.insert(command.name().to_string(), command);
+        }
+    }
+
+    pub fn add_sinks(&mut self, sinks: Vec<Arc<dyn Command>>) {
+        let mut registry = self.sinks.lock().unwrap();
+        for sink in sinks {
+            registry.insert(sink.name().to_string(), sink);
+        }
+    }
+
+    pub fn clone_commands(&self) -> IndexMap<String, Arc<dyn Command>> {
+        self.commands.lock().unwrap().clone()
+    }
+
+    pub fn has_command(&self, name: &str) -> bool {
+        self.commands.lock().unwrap().contains_key(name)
+    }
+
+    pub fn get_command(&self, name: &str) -> Arc<dyn Command> {
+        self.commands.lock().unwrap().get(name).unwrap().clone()
+    }
+
+    pub fn has_sink(&self, name: &str) -> bool {
+        self.sinks.lock().unwrap().contains_key(name)
+    }
+
+    pub fn get_sink(&self, name: &str) -> Arc<dyn Command> {
+        self.sinks.lock().unwrap().get(name).unwrap().clone()
+    }
+
+    // Aliases round-trip through `aliases.txt`, the same ad-hoc plain-text
+    // persistence `history.txt` already uses, so the body is stored (and
+    // re-parsed on expansion) verbatim rather than as a pre-classified
+    // pipeline, and survives restarts.
+    pub fn add_alias(&self, name: &str, body: &str) {
+        let mut aliases = self.aliases.lock().unwrap();
+        aliases.insert(name.to_string(), body.to_string());
+        save_aliases(&aliases);
+    }
+
+    pub fn get_alias(&self, name: &str) -> Option<String> {
+        self.aliases.lock().unwrap().get(name).cloned()
+    }
+}
+
+fn load_aliases() -> IndexMap<String, String> {
+    let mut aliases = IndexMap::new();
+
+    let file = match File::open(ALIAS_FILE) {
+        Ok(file) => file,
+        Err(_) => return aliases,
+    };
+
+    for line in BufReader::new(file).lines() {
+        let line = match line {
+            Ok(line) => line,
+            Err(_) => continue,
+        };
+
+        if let Some(tab) = line.find('\t') {
+            let name = &line[..tab];
+            let body = &line[tab + 1..];
+            aliases.insert(name.to_string(), body.to_string());
+        }
+    }
+
+    aliases
+}
+
+fn save_aliases(aliases: &IndexMap<String, String>) {
+    let mut file = match File::create(ALIAS_FILE) {
+        Ok(file) => file,
+        Err(_) => return,
+    };
+
+    for (name, body) in aliases {
+        let _ = writeln!(file, "{}\t{}", name, body);
+    }
+}
@@ -0,0 +1,44 @@
+use crate::parser::span::Span;
+
+use language_reporting::Diagnostic as LangDiagnostic;
+
+use std::fmt;
+
+#[derive(Debug)]
+pub struct Diagnostic {
+    pub diagnostic: LangDiagnostic<Span>,
+}
+
+#[derive(Debug)]
+pub enum ShellError {
+    Diagnostic(Diagnostic, String),
+    TypeError(String),
+    MissingProperty { subpath: String, expr: String },
+    String(String),
+
+    // Input ended (Ctrl-D) while the readline loop was still buffering an
+    // unterminated command (an open quote/bracket, or a trailing pipe).
+    Incomplete,
+}
+
+impl ShellError {
+    pub fn string(s: impl Into<String>) -> ShellError {
+        ShellError::String(s.into())
+    }
+}
+
+impl fmt::Display for ShellError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ShellError::Diagnostic(_, source) => write!(f, "{}", source),
+            ShellError::TypeError(desc) => write!(f, "TypeError: {}", desc),
+            ShellError::MissingProperty { subpath, .. } => {
+                write!(f, "Missing property {}", subpath)
+            }
+            ShellError::String(s) => write!(f, "{}", s),
+            ShellError::Incomplete => write!(f, "Unexpected end of input"),
+        }
+    }
+}
+
+impl std::error::Error for ShellError {}
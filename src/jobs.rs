@@ -0,0 +1,94 @@
+use async_std::task::JoinHandle;
+use indexmap::IndexMap;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JobStatus {
+    Running,
+    Finished,
+}
+
+pub struct Job {
+    handle: Option<JoinHandle<()>>,
+    status: JobStatus,
+}
+
+#[derive(Default)]
+pub struct JobTable {
+    next_id: usize,
+    jobs: IndexMap<usize, Job>,
+}
+
+impl JobTable {
+    pub fn new() -> JobTable {
+        JobTable {
+            next_id: 0,
+            jobs: IndexMap::new(),
+        }
+    }
+
+    // Claims a job id and records it as running *before* the pipeline is
+    // spawned, so a pipeline that finishes before `attach` runs can never
+    // race ahead of the bookkeeping that's supposed to track it.
+    pub fn reserve(&mut self) -> usize {
+        self.next_id += 1;
+        let id = self.next_id;
+
+        self.jobs.insert(
+            id,
+            Job {
+                handle: None,
+                status: JobStatus::Running,
+            },
+        );
+
+        id
+    }
+
+    pub fn attach(&mut self, id: usize, handle: JoinHandle<()>) {
+        if let Some(job) = self.jobs.get_mut(&id) {
+            job.handle = Some(handle);
+        }
+    }
+
+    pub fn finish(&mut self, id: usize) {
+        if let Some(job) = self.jobs.get_mut(&id) {
+            job.status = JobStatus::Finished;
+        }
+    }
+
+    pub fn list(&self) -> Vec<(usize, JobStatus)> {
+        self.jobs.iter().map(|(id, job)| (*id, job.status)).collect()
+    }
+
+    pub fn take_handle(&mut self, id: usize) -> Option<JoinHandle<()>> {
+        self.jobs.get_mut(&id).and_then(|job| job.handle.take())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Pins the ordering the reserve-before-spawn fix depends on: a job that
+    // finishes before its handle is ever attached (the race this was written
+    // to close) must stay recorded as finished rather than being resurrected
+    // as running.
+    #[test]
+    fn finish_before_attach_does_not_resurrect_the_job_as_running() {
+        let mut table = JobTable::new();
+        let id = table.reserve();
+
+        table.finish(id);
+        table.attach(id, async_std::task::spawn(async {}));
+
+        assert_eq!(table.list(), vec![(id, JobStatus::Finished)]);
+    }
+
+    #[test]
+    fn reserve_assigns_increasing_ids_starting_at_one() {
+        let mut table = JobTable::new();
+
+        assert_eq!(table.reserve(), 1);
+        assert_eq!(table.reserve(), 2);
+    }
+}